@@ -8,7 +8,7 @@ use first::utils;
 fn bench_frequency_O(c: &mut Criterion) {
     let mut group = c.benchmark_group("frequency");
     for size in [100, 1_000, 10_000].iter() {
-        let s: String = std::iter::repeat("ABCD").take(*size / 4).collect();
+        let s: Vec<char> = std::iter::repeat("ABCD").take(*size / 4).collect::<String>().chars().collect();
         group.bench_with_input(criterion::BenchmarkId::new("Size", size), size, |b, &_| {
             b.iter(|| utils::frequency(black_box(&s)))
         });
@@ -45,5 +45,33 @@ fn bench_entropy_O(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_frequency_O, bench_freq_to_prob_O, bench_entropy_O);
+fn bench_decode_trie_vs_string_O(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode_trie_vs_string");
+
+    let codes: HashMap<char, String> = [
+        ('A', "0".to_string()),
+        ('B', "10".to_string()),
+        ('C', "110".to_string()),
+        ('D', "111".to_string()),
+    ]
+    .into_iter()
+    .collect();
+
+    for size in [100, 1_000, 10_000].iter() {
+        let symbols: Vec<char> = std::iter::repeat("ABCD".chars()).flatten().take(*size).collect();
+        let encoded = utils::encoder(&symbols, &codes).unwrap();
+        let trie = utils::build_decoding_trie(&codes).unwrap();
+
+        group.bench_with_input(criterion::BenchmarkId::new("trie", size), size, |b, &_| {
+            b.iter(|| utils::decode_with_trie(black_box(&encoded), &trie))
+        });
+
+        group.bench_with_input(criterion::BenchmarkId::new("string_accumulation", size), size, |b, &_| {
+            b.iter(|| utils::decoder(black_box(&encoded), &codes))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_frequency_O, bench_freq_to_prob_O, bench_entropy_O, bench_decode_trie_vs_string_O);
 criterion_main!(benches);
@@ -10,6 +10,9 @@ pub enum SymbolMappingError {
     SymbolNotFoundInCodes(char),
     ExtraSymbolInCodes(char),
     UnknownSymbolInString,
+    InvalidContainer(String),
+    IncompleteCodeTree,
+    ZeroExpectedFrequency(char),
     // Add other types of errors as needed
 }
 
@@ -26,6 +29,15 @@ impl fmt::Display for SymbolMappingError {
             SymbolMappingError::UnknownSymbolInString => {
                 write!(f, "Unknown symbol found in the input string.")
             },
+            SymbolMappingError::InvalidContainer(reason) => {
+                write!(f, "Invalid container data: {}", reason)
+            },
+            SymbolMappingError::IncompleteCodeTree => {
+                write!(f, "Code table does not form a complete prefix code.")
+            },
+            SymbolMappingError::ZeroExpectedFrequency(symbol) => {
+                write!(f, "Expected frequency for symbol {} is zero, chi-squared score is undefined.", symbol)
+            },
         }
     }
 }
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use crate::utils::freq_to_prob;
+
+/// An incremental character frequency distribution for data that doesn't fit in a single `&str`,
+/// such as a file or a network stream. Fold chunks in one at a time with `update`, then convert
+/// the running totals into probabilities once the whole stream has been seen.
+///
+/// # Examples
+///
+/// ```
+/// let mut dist = FrequencyDist::new();
+/// dist.update("AAB");
+/// dist.update("BA");
+/// assert_eq!(dist.get('A'), 3);
+/// assert_eq!(dist.get('B'), 2);
+/// assert_eq!(dist.total(), 5);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FrequencyDist {
+    counts: HashMap<char, i32>,
+}
+
+impl FrequencyDist {
+    /// Creates an empty frequency distribution.
+    pub fn new() -> Self {
+        FrequencyDist { counts: HashMap::new() }
+    }
+
+    /// Folds the frequency of each character in `chunk` into the running totals.
+    pub fn update(&mut self, chunk: &str) {
+        for symbol in chunk.chars() {
+            *self.counts.entry(symbol).or_insert(0) += 1;
+        }
+    }
+
+    /// Removes a symbol from the distribution entirely.
+    pub fn remove(&mut self, symbol: char) {
+        self.counts.remove(&symbol);
+    }
+
+    /// Returns the current count for `symbol`, or `0` if it has never been observed.
+    pub fn get(&self, symbol: char) -> i32 {
+        *self.counts.get(&symbol).unwrap_or(&0)
+    }
+
+    /// Returns the total number of symbols observed so far.
+    pub fn total(&self) -> i32 {
+        self.counts.values().sum()
+    }
+
+    /// Converts the accumulated counts into probabilities, ready for `huffman` or `entropy`.
+    pub fn into_probabilities(self) -> HashMap<char, f32> {
+        freq_to_prob(&self.counts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_accumulates_across_chunks() {
+        let mut dist = FrequencyDist::new();
+        dist.update("AAB");
+        dist.update("BA");
+
+        assert_eq!(dist.get('A'), 3);
+        assert_eq!(dist.get('B'), 2);
+        assert_eq!(dist.total(), 5);
+    }
+
+    #[test]
+    fn test_get_unseen_symbol_is_zero() {
+        let dist = FrequencyDist::new();
+        assert_eq!(dist.get('A'), 0);
+    }
+
+    #[test]
+    fn test_remove_drops_symbol_entirely() {
+        let mut dist = FrequencyDist::new();
+        dist.update("AAB");
+        dist.remove('A');
+
+        assert_eq!(dist.get('A'), 0);
+        assert_eq!(dist.total(), 1);
+    }
+
+    #[test]
+    fn test_into_probabilities_matches_freq_to_prob() {
+        let mut dist = FrequencyDist::new();
+        dist.update("AABB");
+
+        let probs = dist.into_probabilities();
+        assert!((probs[&'A'] - 0.5).abs() < f32::EPSILON);
+        assert!((probs[&'B'] - 0.5).abs() < f32::EPSILON);
+    }
+}
@@ -3,9 +3,15 @@ use std::collections::HashMap;
 mod utils;
 mod errors;
 mod nodes;
+mod range;
+mod freq_dist;
+
+fn chars(s: &str) -> Vec<char> {
+    s.chars().collect()
+}
 
 fn main() {
-    let freqs = utils::frequency("ABBA");
+    let freqs = utils::frequency(&chars("ABBA"));
     println!("{:?}", freqs);
 
     let probs = utils::freq_to_prob(&freqs);
@@ -24,7 +30,7 @@ fn main() {
     let expected = utils::expected(&probs, &c);
     println!("Expected Length: {:?}", expected);
 
-    let freqs = utils::frequency("1234567890ABjBA1WROJEX(U@#X(@(#((@((@DKODJWOJEWOJWOeeeeeeeeeeeeeeee aaaaaaaccchhh '{;#@ghjLKJ");
+    let freqs = utils::frequency(&chars("1234567890ABjBA1WROJEX(U@#X(@(#((@((@DKODJWOJEWOJWOeeeeeeeeeeeeeeee aaaaaaaccchhh '{;#@ghjLKJ"));
     println!("{:?}", freqs);
 
     let probs = utils::freq_to_prob(&freqs);
@@ -33,32 +39,33 @@ fn main() {
     let entropy = utils::entropy(&probs);
     println!("Entropy is: {:?}", entropy);
 
-    let code = utils::huffman(&probs);
+    let code = utils::huffman(probs.clone());
     println!("{:#?}", code);
 
-    let expected = utils::expected(&probs, &code);
+    let code_refs: HashMap<char, &str> = code.iter().map(|(&symbol, c)| (symbol, c.as_str())).collect();
+    let expected = utils::expected(&probs, &code_refs);
     println!("Expected Length: {:?}", expected);
 
-    let encoded = utils::encoder("12 each", &code);
+    let encoded = utils::encoder(&chars("12 each"), &code);
     match encoded {
         Ok(encoded_str) => {
             println!("Encoded: {:#?}", encoded_str);
             let decoded = utils::decoder(&encoded_str, &code);
             match decoded {
-                Ok(value) => println!("Decoded: {:#?}", value),
+                Ok(value) => println!("Decoded: {:#?}", value.into_iter().collect::<String>()),
                 Err(e) => println!("An error occurred while decoding: {:?}", e),
             }
         },
         Err(e) => println!("An error occurred while encoding: {:?}", e),
     }
 
-    let encoded = utils::encoder("12 Each", &code);
+    let encoded = utils::encoder(&chars("12 Each"), &code);
     match encoded {
         Ok(encoded_str) => {
             println!("Encoded: {:#?}", encoded_str);
             let decoded = utils::decoder(&encoded_str, &code);
             match decoded {
-                Ok(value) => println!("Decoded: {:#?}", value),
+                Ok(value) => println!("Decoded: {:#?}", value.into_iter().collect::<String>()),
                 Err(e) => println!("An error occurred while decoding: {:?}", e),
             }
         },
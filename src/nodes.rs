@@ -1,20 +1,20 @@
 use std::cmp::Ordering;
 
 #[derive(Debug, PartialEq)]
-pub struct Node {
+pub struct Node<T> {
     pub probability: f32,
-    pub kind: NodeKind,
+    pub kind: NodeKind<T>,
 }
 
-impl Eq for Node {}
+impl<T: PartialEq> Eq for Node<T> {}
 
-impl Ord for Node {
+impl<T: PartialEq> Ord for Node<T> {
     fn cmp(&self, other: &Self) -> Ordering {
         other.probability.partial_cmp(&self.probability).unwrap_or(Ordering::Equal)
     }
 }
 
-impl PartialOrd for Node {
+impl<T: PartialEq> PartialOrd for Node<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
@@ -22,7 +22,7 @@ impl PartialOrd for Node {
 
 
 #[derive(Debug, PartialEq, PartialOrd)]
-pub enum NodeKind {
-    Internal { left: Box<Node>, right: Box<Node> },
-    Leaf { symbol: char },
+pub enum NodeKind<T> {
+    Internal { left: Box<Node<T>>, right: Box<Node<T>> },
+    Leaf { symbol: T },
 }
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use crate::errors::SymbolMappingError;
+
+/// Renormalization threshold: once `range` would underflow usable precision, emit a byte.
+const TOP: u32 = 1 << 24;
+/// Threshold below which `range` is forced to straddle a byte boundary to avoid carry
+/// propagation into already-emitted output (Subbotin's carryless range coder).
+const BOT: u32 = 1 << 16;
+/// All scaled symbol frequencies sum to this power of two.
+const TOTAL: u32 = 1 << 16;
+
+/// Builds a cumulative-frequency table from a probability map, scaled to integer counts
+/// summing to exactly [`TOTAL`], sorted by symbol so encoder and decoder derive the same table.
+/// Every symbol keeps a count of at least 1, so with more than `TOTAL` symbols the sum exceeds it.
+fn cumulative_table(probs: &HashMap<char, f32>) -> Vec<(char, u32, u32)> {
+    let mut symbols: Vec<char> = probs.keys().cloned().collect();
+    symbols.sort();
+
+    if symbols.is_empty() {
+        return Vec::new();
+    }
+
+    let mut freqs: Vec<u32> = symbols.iter().map(|s| ((probs[s] * TOTAL as f32).round() as u32).max(1)).collect();
+
+    let sum: u32 = freqs.iter().sum();
+    if sum != TOTAL {
+        let (largest, _) = freqs.iter().enumerate().max_by_key(|&(_, &f)| f).unwrap();
+        let adjusted = freqs[largest] as i64 + (TOTAL as i64 - sum as i64);
+        freqs[largest] = adjusted.max(1) as u32;
+    }
+    // Clamping at 1 above can still leave the sum over TOTAL when many small probabilities were
+    // rounded up; take the excess from the largest counts one at a time.
+    let mut sum: u32 = freqs.iter().sum();
+    while sum > TOTAL && freqs.iter().any(|&f| f > 1) {
+        let (largest, _) = freqs.iter().enumerate().max_by_key(|&(_, &f)| f).unwrap();
+        freqs[largest] -= 1;
+        sum -= 1;
+    }
+
+    let mut table = Vec::with_capacity(symbols.len());
+    let mut cum = 0u32;
+    for (symbol, freq) in symbols.into_iter().zip(freqs) {
+        table.push((symbol, cum, freq));
+        cum += freq;
+    }
+    table
+}
+
+fn renormalize(low: &mut u32, range: &mut u32, mut emit: impl FnMut(u8)) {
+    while (*low ^ low.wrapping_add(*range)) < TOP
+        || (*range < BOT && { *range = low.wrapping_neg() & (BOT - 1); true })
+    {
+        emit((*low >> 24) as u8);
+        *low <<= 8;
+        *range <<= 8;
+    }
+}
+
+/// Encodes a string using a static range coder built from the given probability distribution.
+///
+/// Unlike Huffman coding, which can only assign whole-bit code lengths, a range coder can spend a
+/// fractional number of bits per symbol, getting within a fraction of a bit of the `entropy` the
+/// crate already computes for skewed distributions.
+///
+/// # Errors
+///
+/// Returns `SymbolMappingError::UnknownSymbolInString` if `s` contains a symbol with no entry in
+/// `probs`.
+pub fn range_encode(s: &str, probs: &HashMap<char, f32>) -> Result<Vec<u8>, SymbolMappingError> {
+    let table = cumulative_table(probs);
+    let index: HashMap<char, (u32, u32)> = table.into_iter().map(|(symbol, cum, freq)| (symbol, (cum, freq))).collect();
+
+    let mut low: u32 = 0;
+    let mut range: u32 = u32::MAX;
+    let mut out = Vec::new();
+
+    for symbol in s.chars() {
+        let &(cum_freq, freq) = index.get(&symbol).ok_or(SymbolMappingError::UnknownSymbolInString)?;
+
+        range /= TOTAL;
+        low = low.wrapping_add(cum_freq * range);
+        range *= freq;
+
+        renormalize(&mut low, &mut range, |byte| out.push(byte));
+    }
+
+    for _ in 0..4 {
+        out.push((low >> 24) as u8);
+        low <<= 8;
+    }
+
+    Ok(out)
+}
+
+/// Decodes a buffer produced by [`range_encode`] back into its original string.
+///
+/// # Arguments
+///
+/// * `bytes` - The range-coded buffer.
+/// * `probs` - The same probability distribution used to encode.
+/// * `len` - The number of symbols to decode. Range-coded output has no built-in terminator, so
+///   the caller must supply how many symbols to expect (e.g. the length of the original string).
+///
+/// # Errors
+///
+/// Returns `SymbolMappingError::UnknownSymbolInString` if the decoded cumulative-frequency value
+/// does not land inside any symbol's interval, which indicates `probs` does not match the
+/// distribution `bytes` was encoded with.
+pub fn range_decode(bytes: &[u8], probs: &HashMap<char, f32>, len: usize) -> Result<String, SymbolMappingError> {
+    let table = cumulative_table(probs);
+
+    let mut pos = 0usize;
+    let mut next_byte = || {
+        let byte = bytes.get(pos).copied().unwrap_or(0);
+        pos += 1;
+        byte
+    };
+
+    let mut low: u32 = 0;
+    let mut range: u32 = u32::MAX;
+    let mut code: u32 = 0;
+    for _ in 0..4 {
+        code = (code << 8) | next_byte() as u32;
+    }
+
+    let mut decoded = String::with_capacity(len);
+    for _ in 0..len {
+        range /= TOTAL;
+        let value = code.wrapping_sub(low) / range;
+
+        let slot = table.partition_point(|&(_, cum, _)| cum <= value);
+        let &(symbol, cum_freq, freq) = slot
+            .checked_sub(1)
+            .and_then(|i| table.get(i))
+            .ok_or(SymbolMappingError::UnknownSymbolInString)?;
+        if value >= cum_freq + freq {
+            return Err(SymbolMappingError::UnknownSymbolInString);
+        }
+
+        decoded.push(symbol);
+
+        low = low.wrapping_add(cum_freq * range);
+        range *= freq;
+
+        renormalize(&mut low, &mut range, |_| {
+            code = (code << 8) | next_byte() as u32;
+        });
+    }
+
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{frequency, freq_to_prob};
+
+    #[test]
+    fn test_range_encode_decode_roundtrip() {
+        let s = "this is an example of a range coded message";
+        let symbols: Vec<char> = s.chars().collect();
+        let probs = freq_to_prob(&frequency(&symbols));
+
+        let encoded = range_encode(s, &probs).unwrap();
+        let decoded = range_decode(&encoded, &probs, s.chars().count()).unwrap();
+
+        assert_eq!(decoded, s);
+    }
+
+    #[test]
+    fn test_range_encode_decode_empty_input() {
+        let probs: HashMap<char, f32> = HashMap::new();
+
+        let encoded = range_encode("", &probs).unwrap();
+        let decoded = range_decode(&encoded, &probs, 0).unwrap();
+
+        assert_eq!(decoded, "");
+    }
+
+    #[test]
+    fn test_range_encode_empty_probs_with_symbols_errors() {
+        let probs: HashMap<char, f32> = HashMap::new();
+
+        let result = range_encode("A", &probs);
+        assert!(matches!(result, Err(SymbolMappingError::UnknownSymbolInString)));
+    }
+
+    #[test]
+    fn test_range_encode_unknown_symbol() {
+        let mut probs = HashMap::new();
+        probs.insert('A', 0.5);
+        probs.insert('B', 0.5);
+
+        let result = range_encode("ABZ", &probs);
+        assert!(matches!(result, Err(SymbolMappingError::UnknownSymbolInString)));
+    }
+
+    #[test]
+    fn test_range_encode_beats_or_matches_whole_bit_huffman() {
+        // A skewed distribution where Huffman is forced to round code lengths up to whole bits.
+        let s = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAB";
+        let symbols: Vec<char> = s.chars().collect();
+        let probs = freq_to_prob(&frequency(&symbols));
+
+        let encoded = range_encode(s, &probs).unwrap();
+        let bits_used = encoded.len() as f32 * 8.0;
+        let entropy_bits = crate::utils::entropy(&probs) * s.chars().count() as f32;
+
+        // The range coder pays a small constant overhead (the 4 flush bytes) but should stay
+        // close to the information-theoretic lower bound rather than the whole-bit Huffman cost.
+        assert!(bits_used < entropy_bits + 64.0);
+
+        let huffman_codes = crate::utils::huffman(probs.clone());
+        let huffman_bits: usize = symbols.iter().map(|symbol| huffman_codes[symbol].len()).sum();
+        assert!(bits_used <= huffman_bits as f32);
+    }
+}
@@ -1,68 +1,73 @@
 use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
 
 use crate::nodes::{Node, NodeKind};
 use crate::errors::SymbolMappingError;
 
+/// A bit-packed buffer produced by [`encode_packed`], paired with the exact number of
+/// meaningful bits it contains (see [`decode_packed`]).
+pub type PackedBits = (Vec<u8>, usize);
 
-/// Calculates the frequency of each character in a given string.
+
+/// Calculates the frequency of each symbol in a given slice.
 ///
 /// # Arguments
 ///
-/// * `s` - A string slice that holds the text you wish to analyze.
+/// * `symbols` - A slice of the symbols you wish to analyze.
 ///
 /// # Returns
 ///
-/// Returns a `HashMap` where the keys are the unique characters found in the string,
-/// and the values are the frequencies of these characters.
+/// Returns a `HashMap` where the keys are the unique symbols found in the slice,
+/// and the values are the frequencies of these symbols.
 ///
 /// # Examples
 ///
 /// ```
-/// let result = frequency("ABBA");
+/// let result = frequency(&['A', 'B', 'B', 'A']);
 /// assert_eq!(result, [('A', 2), ('B', 2)]);
 /// ```
-pub fn frequency(s: &str) -> HashMap<char, i32> {
+pub fn frequency<T: Eq + Hash + Clone>(symbols: &[T]) -> HashMap<T, i32> {
     let mut d = HashMap::new();
-    for symbol in s.chars() {
-        *d.entry(symbol).or_insert(0) += 1;
+    for symbol in symbols {
+        *d.entry(symbol.clone()).or_insert(0) += 1;
     }
     d
 }
 
-/// Converts the frequencies of each character in a given `HashMap` to probabilities.
+/// Converts the frequencies of each symbol in a given `HashMap` to probabilities.
 ///
 /// # Arguments
 ///
-/// * `f` - A reference to a `HashMap` containing character frequencies.
+/// * `f` - A reference to a `HashMap` containing symbol frequencies.
 ///
 /// # Returns
 ///
-/// Returns a new `HashMap` where the keys are the unique characters, and the values are probabilities.
+/// Returns a new `HashMap` where the keys are the unique symbols, and the values are probabilities.
 ///
 /// # Examples
 ///
 /// ```
-/// let freqs = frequency("ABBA");
+/// let freqs = frequency(&['A', 'B', 'B', 'A']);
 /// let probs = freq_to_prob(&freqs);
 /// assert_eq!(probs, [('A', 0.5), ('B', 0.5)]);
 /// ```
-pub fn freq_to_prob(f: &HashMap<char, i32>) -> HashMap<char, f32> {
-    let mut d: HashMap<char, f32> = HashMap::new();
+pub fn freq_to_prob<T: Eq + Hash + Clone>(f: &HashMap<T, i32>) -> HashMap<T, f32> {
+    let mut d: HashMap<T, f32> = HashMap::new();
     let sum: f32 = f.values().sum::<i32>() as f32;
-    for (&symbol, &value) in f.iter() {
-        d.insert(symbol, value as f32 / sum);
+    for (symbol, &value) in f.iter() {
+        d.insert(symbol.clone(), value as f32 / sum);
     }
     d
 }
 
 
-/// Calculates the entropy of a given probability.
+/// Calculates the entropy of a given probability distribution.
 ///
 /// Entropy is the average amount of information contained in each piece of information received
 ///
 /// # Arguments
 ///
-/// * `f` - A reference to a `HashMap` containing character frequencies as `f32`.
+/// * `f` - A reference to a `HashMap` containing symbol probabilities as `f32`.
 ///
 /// # Returns
 ///
@@ -90,7 +95,7 @@ pub fn freq_to_prob(f: &HashMap<char, i32>) -> HashMap<char, f32> {
 /// ```
 ///
 /// Note that the function signature assumes that you've imported `HashMap` from the standard library.
-pub fn entropy(f: &HashMap<char, f32>) -> f32 {
+pub fn entropy<T: Eq + Hash>(f: &HashMap<T, f32>) -> f32 {
     let mut total: f32 = 0.0;
     for &value in f.values() {
         total += value * -value.log2();
@@ -133,8 +138,51 @@ pub fn expected(f: &HashMap<char, f32>, c: &HashMap<char, &str>) -> Result<f32,
     Ok(total)
 }
 
-pub fn huffman(probs: HashMap<char, f32>) -> HashMap<char, String> {
-    let mut heap: BinaryHeap<Node> = probs
+/// Scores how closely an observed frequency distribution matches an expected one, using
+/// Pearson's chi-squared statistic: `Σ (observed_i − expected_i)² / expected_i`, where
+/// `expected_i` is the expected *count* for symbol `i`, obtained by scaling `expected`'s
+/// reference probability by the total number of observations in `observed`.
+///
+/// Lower scores indicate a closer match. This is the classic way to score candidate plaintexts
+/// when breaking a single-byte XOR cipher: decrypt with each candidate key, run this statistic
+/// against an English-letter reference table, and keep the lowest-scoring candidate.
+///
+/// # Arguments
+///
+/// * `observed` - A reference to a `HashMap` containing observed symbol counts.
+/// * `expected` - A reference to a `HashMap` containing the reference probability of each symbol.
+///
+/// # Errors
+///
+/// Returns `SymbolMappingError::SymbolNotFoundInCodes` if a symbol in `observed` has no entry in
+/// `expected`, `SymbolMappingError::ExtraSymbolInCodes` if a symbol in `expected` is never
+/// observed, or `SymbolMappingError::ZeroExpectedFrequency` if a symbol's expected count works
+/// out to zero, which would otherwise make the term `diff² / expected_count` undefined.
+pub fn chi_squared(observed: &HashMap<char, i32>, expected: &HashMap<char, f32>) -> Result<f32, SymbolMappingError> {
+    let total: f32 = observed.values().sum::<i32>() as f32;
+
+    let mut score: f32 = 0.0;
+    for (&symbol, &obs) in observed.iter() {
+        let &prob = expected.get(&symbol).ok_or(SymbolMappingError::SymbolNotFoundInCodes(symbol))?;
+        let expected_count = prob * total;
+        if expected_count == 0.0 {
+            return Err(SymbolMappingError::ZeroExpectedFrequency(symbol));
+        }
+        let diff = obs as f32 - expected_count;
+        score += diff * diff / expected_count;
+    }
+
+    for &symbol in expected.keys() {
+        if !observed.contains_key(&symbol) {
+            return Err(SymbolMappingError::ExtraSymbolInCodes(symbol));
+        }
+    }
+
+    Ok(score)
+}
+
+pub fn huffman<T: Eq + Hash + Clone>(probs: HashMap<T, f32>) -> HashMap<T, String> {
+    let mut heap: BinaryHeap<Node<T>> = probs
         .into_iter()
         .map(|(symbol, probability)| Node {
             probability,
@@ -154,13 +202,17 @@ pub fn huffman(probs: HashMap<char, f32>) -> HashMap<char, String> {
 
     let root = heap.pop().unwrap();
     let mut huffman_codes = HashMap::new();
-    generate_codes(root, String::new(), &mut huffman_codes);
+    // A single-symbol alphabet collapses to one leaf with no internal nodes above it, so the
+    // natural top-down traversal would assign it the empty string. Every symbol still needs at
+    // least one bit to round-trip through `encoder`/`decoder`, so seed it with "0" instead.
+    let initial_code = if matches!(root.kind, NodeKind::Leaf { .. }) { String::from("0") } else { String::new() };
+    generate_codes(root, initial_code, &mut huffman_codes);
 
     huffman_codes
 }
 
 
-fn generate_codes(node: Node, current_code: String, huffman_codes: &mut HashMap<char, String>) {
+fn generate_codes<T: Eq + Hash>(node: Node<T>, current_code: String, huffman_codes: &mut HashMap<T, String>) {
     match node.kind {
         NodeKind::Leaf { symbol } => {
             huffman_codes.insert(symbol, current_code);
@@ -172,12 +224,338 @@ fn generate_codes(node: Node, current_code: String, huffman_codes: &mut HashMap<
     }
 }
 
+/// Encodes a sequence of symbols into its variable-length code representation.
+///
+/// # Arguments
+///
+/// * `symbols` - The symbols to encode.
+/// * `c` - A reference to a `HashMap` mapping each symbol to its code, given as a `String` of '0'/'1' characters.
+///
+/// # Errors
+///
+/// Returns `SymbolMappingError::UnknownSymbolInString` if `symbols` contains a symbol that has no entry in `c`.
+pub fn encoder<T: Eq + Hash>(symbols: &[T], c: &HashMap<T, String>) -> Result<String, SymbolMappingError> {
+    let mut encoded = String::new();
+    for symbol in symbols {
+        let code = c.get(symbol).ok_or(SymbolMappingError::UnknownSymbolInString)?;
+        encoded.push_str(code);
+    }
+    Ok(encoded)
+}
+
+/// Decodes a string of '0'/'1' characters back into its original symbols.
+///
+/// Walks the bit string one character at a time, accumulating a candidate code and testing it
+/// against `c` on every step, until a match is found.
+///
+/// # Arguments
+///
+/// * `s` - The encoded bit string to decode.
+/// * `c` - A reference to a `HashMap` mapping each symbol to its code.
+///
+/// # Errors
+///
+/// Returns `SymbolMappingError::UnknownSymbolInString` if the bits are exhausted without the
+/// accumulated code ever matching an entry in `c`.
+pub fn decoder<T: Eq + Hash + Clone>(s: &str, c: &HashMap<T, String>) -> Result<Vec<T>, SymbolMappingError> {
+    let reverse: HashMap<&str, T> = c.iter().map(|(symbol, code)| (code.as_str(), symbol.clone())).collect();
+
+    let mut decoded = Vec::new();
+    let mut current_code = String::new();
+    for bit in s.chars() {
+        current_code.push(bit);
+        if let Some(symbol) = reverse.get(current_code.as_str()) {
+            decoded.push(symbol.clone());
+            current_code.clear();
+        }
+    }
+
+    if !current_code.is_empty() {
+        return Err(SymbolMappingError::UnknownSymbolInString);
+    }
+
+    Ok(decoded)
+}
+
+/// Encodes a sequence of symbols into a bit-packed buffer instead of a `String` of '0'/'1' characters.
+///
+/// Each code bit is written MSB-first into the returned `Vec<u8>`. The final byte may be
+/// padded with zero bits, so the exact number of meaningful bits is returned alongside the
+/// buffer and must be passed back into [`decode_packed`] to drop that padding.
+///
+/// # Arguments
+///
+/// * `symbols` - The symbols to encode.
+/// * `c` - A reference to a `HashMap` mapping each symbol to its code.
+///
+/// # Returns
+///
+/// A tuple of the packed bytes and the number of meaningful bits they contain.
+///
+/// # Errors
+///
+/// Returns `SymbolMappingError::UnknownSymbolInString` if `symbols` contains a symbol that has no
+/// entry in `c`.
+pub fn encode_packed<T: Eq + Hash>(symbols: &[T], c: &HashMap<T, String>) -> Result<PackedBits, SymbolMappingError> {
+    let bits = encoder(symbols, c)?;
+
+    let mut buffer = Vec::with_capacity(bits.len().div_ceil(8));
+    let mut byte = 0u8;
+    let mut filled = 0u32;
+    for bit in bits.chars() {
+        byte = (byte << 1) | (bit == '1') as u8;
+        filled += 1;
+        if filled == 8 {
+            buffer.push(byte);
+            byte = 0;
+            filled = 0;
+        }
+    }
+    if filled > 0 {
+        byte <<= 8 - filled;
+        buffer.push(byte);
+    }
+
+    Ok((buffer, bits.len()))
+}
+
+/// Decodes a buffer produced by [`encode_packed`] back into its original symbols.
+///
+/// # Arguments
+///
+/// * `bytes` - The packed bit buffer.
+/// * `bit_len` - The exact number of meaningful bits in `bytes`, as returned by `encode_packed`.
+/// * `c` - A reference to a `HashMap` mapping each symbol to its code.
+///
+/// # Errors
+///
+/// Returns `SymbolMappingError::UnknownSymbolInString` if the bits are exhausted without the
+/// accumulated code ever matching an entry in `c`.
+pub fn decode_packed<T: Eq + Hash + Clone>(bytes: &[u8], bit_len: usize, c: &HashMap<T, String>) -> Result<Vec<T>, SymbolMappingError> {
+    let mut bits = String::with_capacity(bit_len);
+    for &byte in bytes {
+        for i in (0..8).rev() {
+            if bits.len() == bit_len {
+                break;
+            }
+            bits.push(if (byte >> i) & 1 == 1 { '1' } else { '0' });
+        }
+    }
+
+    decoder(&bits, c)
+}
+
+enum TrieBuilder<T> {
+    Leaf(T),
+    Internal(Option<Box<TrieBuilder<T>>>, Option<Box<TrieBuilder<T>>>),
+}
+
+impl<T> TrieBuilder<T> {
+    fn new_internal() -> Self {
+        TrieBuilder::Internal(None, None)
+    }
+}
+
+/// Builds a decoding trie from a code table, reusing the existing `NodeKind::{Internal, Leaf}`
+/// shape: each internal node has `left`/`right` children indexed by bit 0/1, and leaves hold a
+/// symbol. [`decode_with_trie`] then walks one node per input bit, which avoids the repeated
+/// code-table lookups [`decoder`] performs for every bit.
+///
+/// # Errors
+///
+/// Returns `SymbolMappingError::IncompleteCodeTree` if `codes` is not a complete prefix code
+/// (some branch of the trie is never terminated by a leaf, or a code is a prefix of another).
+/// This does not happen for code tables produced by `huffman` or `canonical_codes` over two or
+/// more symbols. A single-symbol table, whose lone code is "0", leaves the trie's '1' branch
+/// empty and is rejected with this error.
+pub fn build_decoding_trie<T: Clone>(codes: &HashMap<T, String>) -> Result<Node<T>, SymbolMappingError> {
+    let mut root = TrieBuilder::new_internal();
+
+    for (symbol, code) in codes {
+        insert_code(&mut root, code.as_bytes(), symbol.clone())?;
+    }
+
+    finalize_trie(root)
+}
+
+fn insert_code<T: Clone>(node: &mut TrieBuilder<T>, bits: &[u8], symbol: T) -> Result<(), SymbolMappingError> {
+    let (&bit, rest) = bits.split_first().ok_or(SymbolMappingError::IncompleteCodeTree)?;
+
+    let TrieBuilder::Internal(left, right) = node else {
+        return Err(SymbolMappingError::IncompleteCodeTree);
+    };
+    let slot = if bit == b'0' { left } else { right };
+
+    if rest.is_empty() {
+        if slot.is_some() {
+            return Err(SymbolMappingError::IncompleteCodeTree);
+        }
+        *slot = Some(Box::new(TrieBuilder::Leaf(symbol)));
+        Ok(())
+    } else {
+        if slot.is_none() {
+            *slot = Some(Box::new(TrieBuilder::new_internal()));
+        }
+        insert_code(slot.as_mut().unwrap(), rest, symbol)
+    }
+}
+
+fn finalize_trie<T>(builder: TrieBuilder<T>) -> Result<Node<T>, SymbolMappingError> {
+    match builder {
+        TrieBuilder::Leaf(symbol) => Ok(Node { probability: 0.0, kind: NodeKind::Leaf { symbol } }),
+        TrieBuilder::Internal(left, right) => {
+            let left = left.ok_or(SymbolMappingError::IncompleteCodeTree)?;
+            let right = right.ok_or(SymbolMappingError::IncompleteCodeTree)?;
+            Ok(Node {
+                probability: 0.0,
+                kind: NodeKind::Internal { left: Box::new(finalize_trie(*left)?), right: Box::new(finalize_trie(*right)?) },
+            })
+        }
+    }
+}
+
+/// Decodes a string of '0'/'1' characters using a decoding trie built by [`build_decoding_trie`],
+/// walking one node per input bit and emitting a symbol on reaching a leaf, then resetting to the
+/// root.
+///
+/// # Errors
+///
+/// Returns `SymbolMappingError::UnknownSymbolInString` if the bits are exhausted mid-traversal.
+pub fn decode_with_trie<T: Clone>(s: &str, trie: &Node<T>) -> Result<Vec<T>, SymbolMappingError> {
+    let mut decoded = Vec::new();
+    let mut node = trie;
+
+    for bit in s.chars() {
+        node = match &node.kind {
+            NodeKind::Internal { left, right } => if bit == '0' { left.as_ref() } else { right.as_ref() },
+            NodeKind::Leaf { .. } => return Err(SymbolMappingError::UnknownSymbolInString),
+        };
+
+        if let NodeKind::Leaf { symbol } = &node.kind {
+            decoded.push(symbol.clone());
+            node = trie;
+        }
+    }
+
+    if !std::ptr::eq(node, trie) {
+        return Err(SymbolMappingError::UnknownSymbolInString);
+    }
+
+    Ok(decoded)
+}
+
+/// Re-assigns a set of Huffman codes to their canonical form.
+///
+/// Canonical codes are derived purely from the *lengths* of the codes in `codes`: symbols are
+/// sorted by `(code length, symbol)`, then assigned consecutive integer codes of increasing bit
+/// length. The resulting map is equivalent in expected length to `codes`, but because it is
+/// fully determined by the sorted list of `(symbol, length)` pairs, a decoder only needs that
+/// list to reconstruct it instead of the whole map. See [`serialize`] and [`deserialize`].
+pub fn canonical_codes(codes: &HashMap<char, String>) -> HashMap<char, String> {
+    let lengths: Vec<(char, u8)> = codes.iter().map(|(&symbol, code)| (symbol, code.len() as u8)).collect();
+    assign_canonical_codes(lengths)
+}
+
+fn assign_canonical_codes(mut lengths: Vec<(char, u8)>) -> HashMap<char, String> {
+    lengths.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+
+    let mut canonical = HashMap::with_capacity(lengths.len());
+    let mut code: u32 = 0;
+    let mut prev_len: u8 = 0;
+    for (symbol, len) in lengths {
+        code <<= len - prev_len;
+        canonical.insert(symbol, format!("{:0width$b}", code, width = len as usize));
+        code += 1;
+        prev_len = len;
+    }
+    canonical
+}
+
+/// Serializes a canonical code table and its packed message into a single, self-describing
+/// container.
+///
+/// The container stores just the per-symbol code *lengths* (not the codes themselves, since
+/// canonical codes are fully determined by their lengths) alongside the packed bits produced by
+/// [`encode_packed`].
+///
+/// # Arguments
+///
+/// * `symbols_with_lengths` - The canonical `(symbol, code length)` pairs, as produced by
+///   calling `canonical_codes` and reading off each code's length.
+/// * `packed_bits` - The `(buffer, bit_len)` pair returned by `encode_packed` against those
+///   canonical codes.
+pub fn serialize(symbols_with_lengths: &[(char, u8)], packed_bits: &PackedBits) -> Vec<u8> {
+    let (buffer, bit_len) = packed_bits;
+
+    let mut container = Vec::with_capacity(4 + symbols_with_lengths.len() * 5 + 8 + buffer.len());
+    container.extend_from_slice(&(symbols_with_lengths.len() as u32).to_le_bytes());
+    for &(symbol, len) in symbols_with_lengths {
+        container.extend_from_slice(&(symbol as u32).to_le_bytes());
+        container.push(len);
+    }
+    container.extend_from_slice(&(*bit_len as u64).to_le_bytes());
+    container.extend_from_slice(buffer);
+
+    container
+}
+
+/// Reconstructs a canonical code table and packed message from a container produced by
+/// [`serialize`].
+///
+/// # Returns
+///
+/// A tuple of the reconstructed code table and the `(buffer, bit_len)` pair to pass to
+/// [`decode_packed`].
+///
+/// # Errors
+///
+/// Returns `SymbolMappingError::InvalidContainer` if `data` is truncated or otherwise malformed.
+pub fn deserialize(data: &[u8]) -> Result<(HashMap<char, String>, PackedBits), SymbolMappingError> {
+    let mut cursor = 0usize;
+    let take = |cursor: &mut usize, n: usize| -> Result<&[u8], SymbolMappingError> {
+        let end = cursor.checked_add(n).ok_or_else(|| SymbolMappingError::InvalidContainer("length overflow".to_string()))?;
+        let slice = data.get(*cursor..end).ok_or_else(|| SymbolMappingError::InvalidContainer("unexpected end of data".to_string()))?;
+        *cursor = end;
+        Ok(slice)
+    };
+
+    let symbol_total = u32::from_le_bytes(take(&mut cursor, 4)?.try_into().unwrap()) as usize;
+
+    // Each symbol table entry is a fixed 5 bytes (4-byte codepoint + 1-byte length), so a claimed
+    // count that couldn't possibly fit in the remaining buffer is malformed input, not a reason to
+    // allocate on its behalf.
+    let max_symbols = (data.len() - cursor) / 5;
+    if symbol_total > max_symbols {
+        return Err(SymbolMappingError::InvalidContainer("symbol count exceeds remaining buffer length".to_string()));
+    }
+
+    let mut lengths = Vec::with_capacity(symbol_total);
+    for _ in 0..symbol_total {
+        let raw_symbol = u32::from_le_bytes(take(&mut cursor, 4)?.try_into().unwrap());
+        let symbol = char::from_u32(raw_symbol)
+            .ok_or_else(|| SymbolMappingError::InvalidContainer(format!("invalid char codepoint {}", raw_symbol)))?;
+        let len = take(&mut cursor, 1)?[0];
+        lengths.push((symbol, len));
+    }
+
+    let bit_len = u64::from_le_bytes(take(&mut cursor, 8)?.try_into().unwrap()) as usize;
+    let buffer = data[cursor..].to_vec();
+
+    let codes = assign_canonical_codes(lengths);
+
+    Ok((codes, (buffer, bit_len)))
+}
+
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use approx::assert_relative_eq;
 
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
     #[cfg(test)]
     mod expected_tests {
         use super::*;
@@ -227,6 +605,74 @@ mod tests {
         }
     }
 
+    #[cfg(test)]
+    mod chi_squared_tests {
+        use super::*;
+
+        #[test]
+        fn test_chi_squared_perfect_match_is_zero() {
+            let mut observed: HashMap<char, i32> = HashMap::new();
+            observed.insert('A', 50);
+            observed.insert('B', 50);
+            let mut expected: HashMap<char, f32> = HashMap::new();
+            expected.insert('A', 0.5);
+            expected.insert('B', 0.5);
+
+            assert_relative_eq!(chi_squared(&observed, &expected).unwrap(), 0.0, epsilon = 1e-6);
+        }
+
+        #[test]
+        fn test_chi_squared_scores_skew() {
+            let mut observed: HashMap<char, i32> = HashMap::new();
+            observed.insert('A', 90);
+            observed.insert('B', 10);
+            let mut expected: HashMap<char, f32> = HashMap::new();
+            expected.insert('A', 0.5);
+            expected.insert('B', 0.5);
+
+            // expected counts are 50/50, observed is 90/10:
+            // (90-50)^2/50 + (10-50)^2/50 = 32 + 32 = 64
+            assert_relative_eq!(chi_squared(&observed, &expected).unwrap(), 64.0, epsilon = 1e-4);
+        }
+
+        #[test]
+        fn test_chi_squared_symbol_missing_from_expected() {
+            let mut observed: HashMap<char, i32> = HashMap::new();
+            observed.insert('A', 10);
+            observed.insert('Z', 5);
+            let mut expected: HashMap<char, f32> = HashMap::new();
+            expected.insert('A', 1.0);
+
+            let result = chi_squared(&observed, &expected);
+            assert!(matches!(result, Err(SymbolMappingError::SymbolNotFoundInCodes('Z'))));
+        }
+
+        #[test]
+        fn test_chi_squared_symbol_missing_from_observed() {
+            let mut observed: HashMap<char, i32> = HashMap::new();
+            observed.insert('A', 10);
+            let mut expected: HashMap<char, f32> = HashMap::new();
+            expected.insert('A', 0.5);
+            expected.insert('B', 0.5);
+
+            let result = chi_squared(&observed, &expected);
+            assert!(matches!(result, Err(SymbolMappingError::ExtraSymbolInCodes('B'))));
+        }
+
+        #[test]
+        fn test_chi_squared_zero_expected_frequency_errors() {
+            let mut observed: HashMap<char, i32> = HashMap::new();
+            observed.insert('A', 10);
+            observed.insert('Q', 0);
+            let mut expected: HashMap<char, f32> = HashMap::new();
+            expected.insert('A', 1.0);
+            expected.insert('Q', 0.0);
+
+            let result = chi_squared(&observed, &expected);
+            assert!(matches!(result, Err(SymbolMappingError::ZeroExpectedFrequency('Q'))));
+        }
+    }
+
     #[cfg(test)]
     mod entropy_tests {
         use super::*;
@@ -268,74 +714,266 @@ mod tests {
         use super::*;
         #[test]
         fn test_frequency_empty_string() {
-            let s = "";
             let expected: HashMap<char, i32> = HashMap::new();
-            assert_eq!(frequency(s), expected);
+            assert_eq!(frequency(&chars("")), expected);
         }
 
         #[test]
         fn test_frequency_single_character() {
-            let s = "A";
             let mut expected = HashMap::new();
             expected.insert('A', 1);
-            assert_eq!(frequency(s), expected);
+            assert_eq!(frequency(&chars("A")), expected);
         }
 
         #[test]
         fn test_frequency_unique_characters() {
-            let s = "ABC";
             let mut expected = HashMap::new();
             expected.insert('A', 1);
             expected.insert('B', 1);
             expected.insert('C', 1);
-            assert_eq!(frequency(s), expected);
+            assert_eq!(frequency(&chars("ABC")), expected);
         }
 
         #[test]
         fn test_frequency_all_same_characters() {
-            let s = "AAA";
             let mut expected = HashMap::new();
             expected.insert('A', 3);
-            assert_eq!(frequency(s), expected);
+            assert_eq!(frequency(&chars("AAA")), expected);
         }
 
         #[test]
         fn test_frequency_mixed_characters() {
-            let s = "AABBC";
             let mut expected = HashMap::new();
             expected.insert('A', 2);
             expected.insert('B', 2);
             expected.insert('C', 1);
-            assert_eq!(frequency(s), expected);
+            assert_eq!(frequency(&chars("AABBC")), expected);
         }
 
         #[test]
         fn test_frequency_case_sensitivity() {
-            let s = "Aa";
             let mut expected = HashMap::new();
             expected.insert('A', 1);
             expected.insert('a', 1);
-            assert_eq!(frequency(s), expected);
+            assert_eq!(frequency(&chars("Aa")), expected);
         }
 
         #[test]
         fn test_frequency_non_alphabetic_characters() {
-            let s = "A1 !";
             let mut expected = HashMap::new();
             expected.insert('A', 1);
             expected.insert('1', 1);
             expected.insert(' ', 1);
             expected.insert('!', 1);
-            assert_eq!(frequency(s), expected);
+            assert_eq!(frequency(&chars("A1 !")), expected);
         }
 
         #[test]
         fn test_frequency_unicode_characters() {
-            let s = "AðŸ˜€";
             let mut expected = HashMap::new();
             expected.insert('A', 1);
             expected.insert('ðŸ˜€', 1);
-            assert_eq!(frequency(s), expected);
+            assert_eq!(frequency(&chars("AðŸ˜€")), expected);
+        }
+
+        #[test]
+        fn test_frequency_non_char_symbols() {
+            let mut expected = HashMap::new();
+            expected.insert(1u8, 2);
+            expected.insert(2u8, 1);
+            assert_eq!(frequency(&[1u8, 2u8, 1u8]), expected);
         }
     }
-}
\ No newline at end of file
+
+    #[cfg(test)]
+    mod encoder_decoder_tests {
+        use super::*;
+
+        fn codes() -> HashMap<char, String> {
+            [('A', "0".to_string()), ('B', "10".to_string()), ('C', "11".to_string())]
+                .into_iter()
+                .collect()
+        }
+
+        #[test]
+        fn test_encoder_roundtrips_through_decoder() {
+            let c = codes();
+            let encoded = encoder(&chars("ABCA"), &c).unwrap();
+            assert_eq!(encoded, "010110");
+            assert_eq!(decoder(&encoded, &c).unwrap(), chars("ABCA"));
+        }
+
+        #[test]
+        fn test_encoder_unknown_symbol() {
+            let c = codes();
+            let result = encoder(&chars("ABZ"), &c);
+            assert!(matches!(result, Err(SymbolMappingError::UnknownSymbolInString)));
+        }
+
+        #[test]
+        fn test_decoder_incomplete_code() {
+            let c = codes();
+            // A trailing "1" can never complete a valid code.
+            let result = decoder("01", &c);
+            assert!(matches!(result, Err(SymbolMappingError::UnknownSymbolInString)));
+        }
+
+        #[test]
+        fn test_encode_packed_roundtrips_through_decode_packed() {
+            let c = codes();
+            let (packed, bit_len) = encode_packed(&chars("ABCA"), &c).unwrap();
+            assert_eq!(bit_len, 6);
+            assert_eq!(packed, vec![0b01011000]);
+            assert_eq!(decode_packed(&packed, bit_len, &c).unwrap(), chars("ABCA"));
+        }
+
+        #[test]
+        fn test_encode_packed_spans_multiple_bytes() {
+            let c = codes();
+            let s = chars("ABCABCABCA");
+            let (packed, bit_len) = encode_packed(&s, &c).unwrap();
+            assert_eq!(decode_packed(&packed, bit_len, &c).unwrap(), s);
+        }
+
+        #[test]
+        fn test_encoder_decoder_non_char_symbols() {
+            let c: HashMap<u8, String> = [(0u8, "0".to_string()), (1u8, "10".to_string()), (2u8, "11".to_string())]
+                .into_iter()
+                .collect();
+            let symbols = vec![1u8, 0u8, 2u8];
+            let encoded = encoder(&symbols, &c).unwrap();
+            assert_eq!(decoder(&encoded, &c).unwrap(), symbols);
+        }
+
+        #[test]
+        fn test_huffman_single_symbol_gets_a_nonempty_code() {
+            let probs = freq_to_prob(&frequency(&chars("AAAA")));
+            let codes = huffman(probs);
+            assert_eq!(codes[&'A'], "0");
+
+            let encoded = encoder(&chars("AAAA"), &codes).unwrap();
+            assert_eq!(decoder(&encoded, &codes).unwrap(), chars("AAAA"));
+        }
+
+        #[test]
+        fn test_huffman_single_non_char_symbol_gets_a_nonempty_code() {
+            let symbols = vec![7u8, 7u8, 7u8];
+            let probs = freq_to_prob(&frequency(&symbols));
+            let codes = huffman(probs);
+            assert_eq!(codes[&7u8], "0");
+
+            let encoded = encoder(&symbols, &codes).unwrap();
+            assert_eq!(decoder(&encoded, &codes).unwrap(), symbols);
+        }
+    }
+
+    #[cfg(test)]
+    mod trie_tests {
+        use super::*;
+
+        fn codes() -> HashMap<char, String> {
+            [('A', "0".to_string()), ('B', "10".to_string()), ('C', "11".to_string())]
+                .into_iter()
+                .collect()
+        }
+
+        #[test]
+        fn test_decode_with_trie_matches_decoder() {
+            let c = codes();
+            let trie = build_decoding_trie(&c).unwrap();
+            let encoded = encoder(&chars("ABCA"), &c).unwrap();
+
+            assert_eq!(decode_with_trie(&encoded, &trie).unwrap(), decoder(&encoded, &c).unwrap());
+        }
+
+        #[test]
+        fn test_decode_with_trie_incomplete_code() {
+            let c = codes();
+            let trie = build_decoding_trie(&c).unwrap();
+            // A trailing "1" can never complete a valid code.
+            let result = decode_with_trie("01", &trie);
+            assert!(matches!(result, Err(SymbolMappingError::UnknownSymbolInString)));
+        }
+
+        #[test]
+        fn test_build_decoding_trie_rejects_prefix_collision() {
+            let mut c = HashMap::new();
+            c.insert('A', "0".to_string());
+            c.insert('B', "01".to_string()); // "0" is a prefix of "01"
+            let result = build_decoding_trie(&c);
+            assert!(matches!(result, Err(SymbolMappingError::IncompleteCodeTree)));
+        }
+    }
+
+    #[cfg(test)]
+    mod canonical_tests {
+        use super::*;
+
+        #[test]
+        fn test_canonical_codes_preserves_lengths_and_decodability() {
+            let probs = freq_to_prob(&frequency(&chars("AAAAABBBCCD")));
+            let codes = huffman(probs);
+            let canonical = canonical_codes(&codes);
+
+            for (symbol, code) in &codes {
+                assert_eq!(canonical[symbol].len(), code.len());
+            }
+
+            let encoded = encoder(&chars("AABCD"), &canonical).unwrap();
+            assert_eq!(decoder(&encoded, &canonical).unwrap(), chars("AABCD"));
+        }
+
+        #[test]
+        fn test_canonical_codes_are_ordered_by_length_then_symbol() {
+            let mut codes = HashMap::new();
+            codes.insert('A', "11".to_string());
+            codes.insert('B', "100".to_string());
+            codes.insert('C', "101".to_string());
+            codes.insert('D', "0".to_string());
+
+            let canonical = canonical_codes(&codes);
+            assert_eq!(canonical[&'D'], "0");
+            assert_eq!(canonical[&'A'], "10");
+            assert_eq!(canonical[&'B'], "110");
+            assert_eq!(canonical[&'C'], "111");
+        }
+    }
+
+    #[cfg(test)]
+    mod container_tests {
+        use super::*;
+
+        #[test]
+        fn test_serialize_deserialize_roundtrip() {
+            let mut codes = HashMap::new();
+            codes.insert('A', "11".to_string());
+            codes.insert('B', "100".to_string());
+            codes.insert('C', "101".to_string());
+            codes.insert('D', "0".to_string());
+            let canonical = canonical_codes(&codes);
+
+            let lengths: Vec<(char, u8)> = canonical.iter().map(|(&s, c)| (s, c.len() as u8)).collect();
+            let packed = encode_packed(&chars("DABCDA"), &canonical).unwrap();
+
+            let container = serialize(&lengths, &packed);
+            let (restored_codes, restored_packed) = deserialize(&container).unwrap();
+
+            assert_eq!(restored_codes, canonical);
+            assert_eq!(restored_packed, packed);
+            assert_eq!(decode_packed(&restored_packed.0, restored_packed.1, &restored_codes).unwrap(), chars("DABCDA"));
+        }
+
+        #[test]
+        fn test_deserialize_truncated_container() {
+            let result = deserialize(&[1, 0, 0, 0]);
+            assert!(matches!(result, Err(SymbolMappingError::InvalidContainer(_))));
+        }
+
+        #[test]
+        fn test_deserialize_rejects_symbol_count_exceeding_buffer() {
+            // Claims ~4.3 billion symbol entries with no data behind it, rather than 4 bytes.
+            let result = deserialize(&[0xFF, 0xFF, 0xFF, 0xFF]);
+            assert!(matches!(result, Err(SymbolMappingError::InvalidContainer(_))));
+        }
+    }
+}